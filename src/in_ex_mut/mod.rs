@@ -1,4 +1,17 @@
-use core::cell::{Cell, RefCell, UnsafeCell};
+use core::cell::{BorrowError, BorrowMutError, Cell, LazyCell, OnceCell, RefCell, UnsafeCell};
+use core::convert::Infallible;
+use core::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicU16, AtomicU32, AtomicU8, Ordering,
+};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, RwLock};
 
 use crate::refs::*;
 
@@ -30,10 +43,263 @@ pub struct CellFamily;
 /// [here]: https://doc.rust-lang.org/reference/interior-mutability.html
 pub struct RefCellFamily;
 
+/// The error returned by [`RefCellFamily`]'s [`TryAccess`] methods when the requested
+/// borrow conflicts with one already in progress.
+///
+/// Mirrors the two borrow-checking failures [`RefCell`] can report: [`BorrowError`] for
+/// the read side ([`TryAccess::try_copy_inner`]/[`TryAccess::try_clone_inner`]) and
+/// [`BorrowMutError`] for the write side ([`TryAccess::try_set`]).
+#[derive(Debug)]
+pub enum RefCellBorrowError {
+    /// A shared borrow (via [`RefCell::try_borrow`]) failed because a mutable borrow is
+    /// currently active.
+    Borrow(BorrowError),
+    /// A mutable borrow (via [`RefCell::try_borrow_mut`]) failed because another borrow
+    /// is currently active.
+    BorrowMut(BorrowMutError),
+}
+
+/// The type that represents the [`OnceCell`] interior mutability wrapper.
+///
+/// Unlike the other families in this module, [`OnceCellFamily`] does *not* implement
+/// [`MutFamily`]: [`MutFamily::get_mut`]/[`MutFamily::into_inner`] both promise to always
+/// succeed, but [`OnceCell`] is, by design, often empty (`OnceCell::get_mut`/
+/// `OnceCell::into_inner` return `Option`). Since `Target<T>` is just a public alias for
+/// `OnceCell<T>`, nothing stops a caller from handing an empty cell to this family's
+/// methods, so there's no invariant this crate could enforce to make the always-succeeds
+/// contract honest. [`OnceCellFamily`] instead exposes access directly through
+/// [`GetOrInit`], whose methods either return `Option`/`Result` or initialize the cell
+/// before handing out a reference.
+///
+/// Learn more about interior and exterior mutability [here].
+///
+/// [here]: https://doc.rust-lang.org/reference/interior-mutability.html
+pub struct OnceCellFamily;
+
+impl OnceCellFamily {
+    /// Constructs a new, uninitialized [`OnceCell`].
+    pub fn uninit<T>() -> OnceCell<T> {
+        OnceCell::new()
+    }
+}
+
+/// The type that represents the [`LazyCell`] interior mutability wrapper.
+///
+/// Unlike the other families in this module, [`LazyCellFamily`] does *not* implement
+/// [`MutFamily`]: [`MutFamily::new`] is handed an already-known `value: T`, whereas
+/// [`LazyCell`]'s type also names its initializer (`LazyCell<T, F>`, with `F` defaulting
+/// to a plain `fn() -> T`), which `Target<T>` has no room for. A plain `fn() -> T` can't
+/// capture an arbitrary runtime `value`, and erasing the type of a closure that does
+/// would need heap allocation that this `no_std` crate doesn't assume is
+/// available. [`LazyCellFamily`] is kept around as a documented gap and instead exposes
+/// lazy access directly through a couple of inherent methods mirroring [`GetOrInit`].
+///
+/// Learn more about interior and exterior mutability [here].
+///
+/// [here]: https://doc.rust-lang.org/reference/interior-mutability.html
+pub struct LazyCellFamily;
+
+impl LazyCellFamily {
+    /// Wraps `f` in a [`LazyCell`] that runs `f` at most once, on first access.
+    pub fn init<T, F>(f: F) -> LazyCell<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        LazyCell::new(f)
+    }
+
+    /// Returns a reference to the wrapped value, forcing initialization via the stored
+    /// initializer if it hasn't run yet.
+    pub fn force<T, F>(ref_: &LazyCell<T, F>) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        LazyCell::force(ref_)
+    }
+}
+
+/// The type that represents the [`Mutex`] cross-thread interior mutability wrapper.
+///
+/// # Poisoning
+///
+/// Every method on this family recovers from a poisoned lock (left behind by a panic
+/// while the lock was held) by using the guarded value anyway, instead of propagating
+/// the poisoning the way [`Mutex`]'s own API does. This keeps the family's methods
+/// infallible like the rest of this module's, at the cost of potentially observing a
+/// value a panicking thread left half-updated.
+///
+/// Learn more about interior and exterior mutability [here].
+///
+/// [here]: https://doc.rust-lang.org/reference/interior-mutability.html
+#[cfg(feature = "std")]
+pub struct MutexFamily;
+
+/// The type that represents the [`RwLock`] cross-thread interior mutability wrapper.
+///
+/// # Poisoning
+///
+/// Every method on this family recovers from a poisoned lock (left behind by a panic
+/// while the lock was held) by using the guarded value anyway, instead of propagating
+/// the poisoning the way [`RwLock`]'s own API does. This keeps the family's methods
+/// infallible like the rest of this module's, at the cost of potentially observing a
+/// value a panicking thread left half-updated.
+///
+/// Learn more about interior and exterior mutability [here].
+///
+/// [here]: https://doc.rust-lang.org/reference/interior-mutability.html
+#[cfg(feature = "std")]
+pub struct RwLockFamily;
+
+#[cfg(feature = "std")]
+impl RwLockFamily {
+    /// Takes the read lock, runs `f` on a shared reference to the wrapped value, then
+    /// releases the lock.
+    ///
+    /// Unlike [`Lock::with_locked`], this only ever needs a shared reference out of the
+    /// guard, so it maps to [`SharedRefFamily`] rather than requiring exclusive access.
+    ///
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    pub fn with_read<T, R, F>(ref_: &RwLock<T>, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let guard = ref_.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&guard)
+    }
+}
+
+/// The trait implemented by the small, closed set of primitive types that have a
+/// corresponding lock-free atomic counterpart in [`core::sync::atomic`].
+pub trait HasAtomic: Copy + Sized {
+    /// The atomic counterpart of `Self`.
+    type Atomic;
+
+    /// Wraps `value` in its atomic counterpart.
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    /// Atomically loads the wrapped value.
+    fn load_atomic(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    /// Atomically stores `value`.
+    fn store_atomic(atomic: &Self::Atomic, value: Self, order: Ordering);
+
+    /// Atomically compares the wrapped value against `current` and, if they match,
+    /// exchanges it for `new`. Returns the previously wrapped value either way.
+    fn compare_exchange_atomic(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_has_atomic {
+    ($($t:ty => $atomic:ty),+ $(,)?) => {
+        $(
+            impl HasAtomic for $t {
+                type Atomic = $atomic;
+
+                fn new_atomic(value: Self) -> Self::Atomic {
+                    <$atomic>::new(value)
+                }
+
+                fn load_atomic(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                fn store_atomic(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order)
+                }
+
+                fn compare_exchange_atomic(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange(current, new, success, failure)
+                }
+            }
+        )+
+    };
+}
+
+impl_has_atomic! {
+    bool => AtomicBool,
+    i8 => AtomicI8,
+    i16 => AtomicI16,
+    i32 => AtomicI32,
+    u8 => AtomicU8,
+    u16 => AtomicU16,
+    u32 => AtomicU32,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl_has_atomic! {
+    i64 => AtomicI64,
+    u64 => AtomicU64,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl_has_atomic! {
+    isize => AtomicIsize,
+    usize => AtomicUsize,
+}
+
+/// The type that represents the [`core::sync::atomic`] lock-free, cross-thread interior
+/// mutability wrapper.
+///
+/// Unlike the other families in this module, [`AtomicFamily`] does *not* implement
+/// [`MutFamily`]: [`MutFamily::Target`] is required to be well-formed for every `T`, but
+/// `T::Atomic` is only a valid type when `T: `[`HasAtomic`], and a `where T: HasAtomic`
+/// clause added only on this impl's `type Target<T> = ...` isn't enough to make
+/// [`MutFamily::new`]'s unconstrained `T` type-check (the bound would have to live on
+/// [`MutFamily::Target`] itself, which would needlessly constrain every other family in
+/// this module). [`AtomicFamily`] instead exposes the same construct/load/store/update
+/// vocabulary directly, bounded by [`HasAtomic`] on each method.
+pub struct AtomicFamily;
+
+impl AtomicFamily {
+    /// Wraps `value` in its atomic counterpart.
+    pub fn wrap<T: HasAtomic>(value: T) -> T::Atomic {
+        T::new_atomic(value)
+    }
+}
+
+/// The trait that provides [`AtomicFamily`]'s load/store/update vocabulary.
+///
+/// Every method is generic over a [`HasAtomic`] primitive `T` and keyed off a shared
+/// reference to `T::Atomic`, mirroring how every atomic type in [`core::sync::atomic`]
+/// mutates through `&self`.
+pub trait Atomic {
+    /// Atomically loads the wrapped value.
+    fn load<T: HasAtomic>(ref_: &T::Atomic, order: Ordering) -> T;
+
+    /// Atomically stores `value`.
+    fn store<T: HasAtomic>(ref_: &T::Atomic, value: T, order: Ordering);
+
+    /// Atomically updates the wrapped value by repeatedly applying `f` to the last
+    /// observed value and attempting a compare-exchange, until either the
+    /// compare-exchange succeeds (returning the previous value as [`Ok`]) or `f`
+    /// returns [`None`] (returning the last observed value as [`Err`]).
+    fn fetch_update<T: HasAtomic, F>(
+        ref_: &T::Atomic,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Result<T, T>
+    where
+        F: FnMut(T) -> Option<T>;
+}
+
 /// The trait whose implementors represent various interior mutability wrappers. The implementor
 /// for the absence of wrapper is [`IdentityFamily`].
 ///
-/// *Note: this trait doesn't cover all interior mutability wrappers, e.g. [`core::cell::LazyCell`]*.
+/// *Note: this trait doesn't cover all interior mutability wrappers, e.g. [`LazyCell`] or
+/// [`OnceCell`]; see [`LazyCellFamily`]'s and [`OnceCellFamily`]'s docs for why*.
 ///
 /// Learn about interior and exterior mutability [here].
 ///
@@ -53,7 +319,6 @@ pub trait MutFamily {
     /// Unwraps the instance of the parameterized type-wrapper.
     fn into_inner<T>(target: Self::Target<T>) -> T;
     /// Returns a mutable reference to the wrapped value.
-    // TODO: consider how to better support OnceCell
     fn get_mut<T>(mut_ref: &mut Self::Target<T>) -> &mut T;
     /// Returns a mutable raw pointer to the wrapped value. Check the safety requirements of the
     /// implementors.
@@ -132,6 +397,129 @@ pub trait Set: MutFamily {
     );
 }
 
+/// The trait whose implementors represent interior mutability wrappers that support
+/// [`Cell`]'s full move-in/move-out vocabulary: overwriting the wrapped value while
+/// handing back the old one ([`Replace::replace`]), moving it out and leaving the
+/// default behind ([`Replace::take`]), and exchanging the contents of two wrappers
+/// ([`Replace::swap`]).
+pub trait Replace: Set {
+    /// Replaces the wrapped value with `value`, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// May panic for some implementors, notably [`RefCellFamily`].
+    fn replace<T>(
+        ref_: <Self::RefMutFamilyAllowingMutation as RefMutFamily>::Ref<'_, Self::Target<T>>,
+        value: T,
+    ) -> T;
+
+    /// Replaces the wrapped value with its [`Default`], returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// May panic for some implementors, notably [`RefCellFamily`].
+    fn take<T: Default>(
+        ref_: <Self::RefMutFamilyAllowingMutation as RefMutFamily>::Ref<'_, Self::Target<T>>,
+    ) -> T;
+
+    /// Swaps the wrapped values of `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// May panic for some implementors, notably [`RefCellFamily`].
+    fn swap<T>(
+        a: <Self::RefMutFamilyAllowingMutation as RefMutFamily>::Ref<'_, Self::Target<T>>,
+        b: <Self::RefMutFamilyAllowingMutation as RefMutFamily>::Ref<'_, Self::Target<T>>,
+    );
+}
+
+/// The trait whose implementors represent interior mutability wrappers that expose
+/// non-panicking, fallible variants of [`Set::set`]/[`CopyInner::copy_inner`]/
+/// [`CloneInner::clone_inner`].
+///
+/// [`RefCellFamily`]'s [`Set`]/[`CopyInner`]/[`CloneInner`] implementations panic on an
+/// active conflicting borrow; [`TryAccess`] reports that conflict as an [`Err`] instead,
+/// via [`TryAccess::Error`].
+///
+/// *Note: [`CellFamily`] doesn't implement this trait, for the same reason it doesn't
+/// implement [`CloneInner`]: [`Cell::get`] requires `T: Copy`, so there's no way to
+/// honor [`TryAccess::try_clone_inner`]'s `T: Clone` bound for non-`Copy` types.*
+pub trait TryAccess: CloneInner + Set {
+    /// The error reported when an access can't be granted.
+    type Error;
+
+    /// Sets the wrapped value to the specified one, reporting a conflicting borrow
+    /// instead of panicking.
+    fn try_set<T>(
+        ref_: <Self::RefMutFamilyAllowingMutation as RefMutFamily>::Ref<'_, Self::Target<T>>,
+        value: T,
+    ) -> Result<(), Self::Error>;
+
+    /// Returns a copy of the wrapped value, reporting a conflicting borrow instead of
+    /// panicking.
+    fn try_copy_inner<T: Copy>(ref_: &Self::Target<T>) -> Result<T, Self::Error>;
+
+    /// Returns a clone of the wrapped value, reporting a conflicting borrow instead of
+    /// panicking.
+    fn try_clone_inner<T: Clone>(ref_: &Self::Target<T>) -> Result<T, Self::Error>;
+}
+
+/// The trait whose implementors represent interior mutability wrappers that can be written
+/// to at most once, after which the wrapped value is available for reading through a shared
+/// reference.
+///
+/// Unlike [`Set`], whose [`Set::RefMutFamilyAllowingMutation`] varies per implementor, every
+/// method here is keyed off a shared reference: a write-once cell like [`OnceCell`] performs
+/// its one-time write through `&self`, so there's no implementor-specific
+/// `RefMutFamilyAllowingMutation` to pick (it would always be [`SharedRefFamily`]).
+///
+/// This trait deliberately isn't a [`MutFamily`] extension: [`MutFamily::get_mut`]/
+/// [`MutFamily::into_inner`] promise to always succeed, which a write-once cell can't
+/// honor (see [`OnceCellFamily`]'s docs), so [`GetOrInit`] carries its own `Target` GAT
+/// instead.
+pub trait GetOrInit {
+    /// The generic associated type (GAT) that allows to wrap types in this family's
+    /// write-once wrapper.
+    type Target<T>;
+
+    /// Returns a reference to the wrapped value, or [`None`] if it hasn't been initialized
+    /// yet.
+    fn get<T>(ref_: &Self::Target<T>) -> Option<&T>;
+
+    /// Returns a reference to the wrapped value, initializing it with `f` first if it
+    /// hasn't been initialized yet.
+    fn get_or_init<T, F>(ref_: &Self::Target<T>, f: F) -> &T
+    where
+        F: FnOnce() -> T;
+
+    /// Sets the wrapped value to `value` if it hasn't been initialized yet, returning a
+    /// reference to the now-set value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `value` back if the cell was already initialized.
+    fn try_insert<T>(ref_: &Self::Target<T>, value: T) -> Result<&T, T>;
+}
+
+/// The trait whose implementors represent interior mutability wrappers that guard the
+/// wrapped value behind a lock rather than granting unsynchronized access to it.
+///
+/// [`Lock::with_locked`] takes the lock, runs `f`, and drops the guard before returning.
+/// The guard can't be returned directly through this crate's GAT-based reference
+/// machinery, since [`MutexGuard`]/[`RwLockWriteGuard`] borrow from the very call that
+/// produces them rather than being nameable ahead of time, so containing the borrow
+/// inside `with_locked` is the only way to expose the lock generically.
+///
+/// [`MutexGuard`]: std::sync::MutexGuard
+/// [`RwLockWriteGuard`]: std::sync::RwLockWriteGuard
+#[cfg(feature = "std")]
+pub trait Lock: Set {
+    /// Locks the wrapped value, runs `f` on a mutable reference to it, then unlocks it.
+    fn with_locked<T, R, F>(ref_: &Self::Target<T>, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R;
+}
+
 impl MutFamily for IdentityFamily {
     type Target<T> = T;
     type RefMutFamilyAllowingMutationUnsafely = MutRefFamily;
@@ -217,6 +605,79 @@ impl MutFamily for RefCellFamily {
     }
 }
 
+#[cfg(feature = "std")]
+impl MutFamily for MutexFamily {
+    type Target<T> = Mutex<T>;
+    type RefMutFamilyAllowingMutationUnsafely = SharedRefFamily;
+
+    fn new<T>(value: T) -> Self::Target<T> {
+        Mutex::new(value)
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`MutexFamily`]'s
+    /// docs.
+    fn into_inner<T>(target: Self::Target<T>) -> T {
+        target
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Momentarily takes the lock (recovering from poisoning, per [`MutexFamily`]'s
+    /// docs) purely to read out the address of the wrapped value; the pointer stays
+    /// valid after the guard is dropped because the addressed memory is owned by the
+    /// [`Mutex`] itself, not the guard. Dereferencing it without separately holding the
+    /// lock is a data race hazard that is on the caller to avoid.
+    fn as_ptr<T>(ref_: &Mutex<T>) -> *mut T {
+        let guard = ref_.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        &*guard as *const T as *mut T
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`MutexFamily`]'s
+    /// docs.
+    fn get_mut<T>(mut_ref: &mut Mutex<T>) -> &mut T {
+        mut_ref
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl MutFamily for RwLockFamily {
+    type Target<T> = RwLock<T>;
+    type RefMutFamilyAllowingMutationUnsafely = SharedRefFamily;
+
+    fn new<T>(value: T) -> Self::Target<T> {
+        RwLock::new(value)
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    fn into_inner<T>(target: Self::Target<T>) -> T {
+        target
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Momentarily takes the read lock (recovering from poisoning, per [`RwLockFamily`]'s
+    /// docs) purely to read out the address of the wrapped value; see
+    /// [`MutexFamily::as_ptr`] for why the pointer remains valid once the guard is
+    /// dropped, and why dereferencing it still requires the caller to hold the lock
+    /// themselves. A read lock suffices here since only the address is needed, not
+    /// exclusive access.
+    fn as_ptr<T>(ref_: &RwLock<T>) -> *mut T {
+        let guard = ref_.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        &*guard as *const T as *mut T
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    fn get_mut<T>(mut_ref: &mut RwLock<T>) -> &mut T {
+        mut_ref
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
 impl CopyInner for IdentityFamily {
     fn copy_inner<T>(ref_: &T) -> T
     where
@@ -303,3 +764,207 @@ impl Set for RefCellFamily {
         *ref_.borrow_mut() = value;
     }
 }
+
+#[cfg(feature = "std")]
+impl Set for MutexFamily {
+    type RefMutFamilyAllowingMutation = SharedRefFamily;
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`MutexFamily`]'s
+    /// docs.
+    fn set<T>(ref_: &Mutex<T>, value: T) {
+        *ref_.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = value;
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`MutexFamily`]'s
+    /// docs.
+    fn set_via_someref<T>(
+        someref: SomeRef<'_, Self::Target<T>, Self::RefMutFamilyAllowingMutation>,
+        value: T,
+    ) {
+        let ref_: &Mutex<T> = someref.into_shared();
+        *ref_.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = value;
+    }
+}
+
+#[cfg(feature = "std")]
+impl Set for RwLockFamily {
+    type RefMutFamilyAllowingMutation = SharedRefFamily;
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    fn set<T>(ref_: &RwLock<T>, value: T) {
+        *ref_.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = value;
+    }
+
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    fn set_via_someref<T>(
+        someref: SomeRef<'_, Self::Target<T>, Self::RefMutFamilyAllowingMutation>,
+        value: T,
+    ) {
+        let ref_: &RwLock<T> = someref.into_shared();
+        *ref_.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = value;
+    }
+}
+
+#[cfg(feature = "std")]
+impl Lock for MutexFamily {
+    /// Recovers from a poisoned lock rather than propagating it; see [`MutexFamily`]'s
+    /// docs.
+    fn with_locked<T, R, F>(ref_: &Mutex<T>, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = ref_.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut guard)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Lock for RwLockFamily {
+    /// Recovers from a poisoned lock rather than propagating it; see [`RwLockFamily`]'s
+    /// docs.
+    fn with_locked<T, R, F>(ref_: &RwLock<T>, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let mut guard = ref_.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut guard)
+    }
+}
+
+impl Replace for IdentityFamily {
+    fn replace<T>(ref_: &mut T, value: T) -> T {
+        core::mem::replace(ref_, value)
+    }
+
+    fn take<T: Default>(ref_: &mut T) -> T {
+        core::mem::take(ref_)
+    }
+
+    fn swap<T>(a: &mut T, b: &mut T) {
+        core::mem::swap(a, b);
+    }
+}
+
+impl Replace for CellFamily {
+    fn replace<T>(ref_: &Cell<T>, value: T) -> T {
+        ref_.replace(value)
+    }
+
+    fn take<T: Default>(ref_: &Cell<T>) -> T {
+        ref_.take()
+    }
+
+    fn swap<T>(a: &Cell<T>, b: &Cell<T>) {
+        Cell::swap(a, b);
+    }
+}
+
+impl Replace for RefCellFamily {
+    fn replace<T>(ref_: &RefCell<T>, value: T) -> T {
+        core::mem::replace(&mut *ref_.borrow_mut(), value)
+    }
+
+    fn take<T: Default>(ref_: &RefCell<T>) -> T {
+        core::mem::take(&mut *ref_.borrow_mut())
+    }
+
+    fn swap<T>(a: &RefCell<T>, b: &RefCell<T>) {
+        core::mem::swap(&mut *a.borrow_mut(), &mut *b.borrow_mut());
+    }
+}
+
+impl TryAccess for IdentityFamily {
+    type Error = Infallible;
+
+    fn try_set<T>(ref_: &mut T, value: T) -> Result<(), Self::Error> {
+        *ref_ = value;
+        Ok(())
+    }
+
+    fn try_copy_inner<T: Copy>(ref_: &T) -> Result<T, Self::Error> {
+        Ok(*ref_)
+    }
+
+    fn try_clone_inner<T: Clone>(ref_: &T) -> Result<T, Self::Error> {
+        Ok(ref_.clone())
+    }
+}
+
+impl TryAccess for RefCellFamily {
+    type Error = RefCellBorrowError;
+
+    fn try_set<T>(ref_: &RefCell<T>, value: T) -> Result<(), Self::Error> {
+        *ref_
+            .try_borrow_mut()
+            .map_err(RefCellBorrowError::BorrowMut)? = value;
+        Ok(())
+    }
+
+    fn try_copy_inner<T: Copy>(ref_: &RefCell<T>) -> Result<T, Self::Error> {
+        ref_.try_borrow()
+            .map(|borrowed| *borrowed)
+            .map_err(RefCellBorrowError::Borrow)
+    }
+
+    fn try_clone_inner<T: Clone>(ref_: &RefCell<T>) -> Result<T, Self::Error> {
+        ref_.try_borrow()
+            .map(|borrowed| borrowed.clone())
+            .map_err(RefCellBorrowError::Borrow)
+    }
+}
+
+impl GetOrInit for OnceCellFamily {
+    type Target<T> = OnceCell<T>;
+
+    fn get<T>(ref_: &OnceCell<T>) -> Option<&T> {
+        ref_.get()
+    }
+
+    fn get_or_init<T, F>(ref_: &OnceCell<T>, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        ref_.get_or_init(f)
+    }
+
+    fn try_insert<T>(ref_: &OnceCell<T>, value: T) -> Result<&T, T> {
+        match ref_.set(value) {
+            Ok(()) => Ok(ref_.get().expect("value was just set")),
+            Err(value) => Err(value),
+        }
+    }
+}
+
+impl Atomic for AtomicFamily {
+    fn load<T: HasAtomic>(ref_: &T::Atomic, order: Ordering) -> T {
+        T::load_atomic(ref_, order)
+    }
+
+    fn store<T: HasAtomic>(ref_: &T::Atomic, value: T, order: Ordering) {
+        T::store_atomic(ref_, value, order);
+    }
+
+    fn fetch_update<T: HasAtomic, F>(
+        ref_: &T::Atomic,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<T, T>
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        let mut current = T::load_atomic(ref_, fetch_order);
+        loop {
+            let next = match f(current) {
+                Some(next) => next,
+                None => return Err(current),
+            };
+            match T::compare_exchange_atomic(ref_, current, next, set_order, fetch_order) {
+                Ok(prev) => return Ok(prev),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+}